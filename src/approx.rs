@@ -0,0 +1,197 @@
+use consts::{ EPSILON };
+
+/// Relative + ULPS based approximate equality.
+///
+/// A single fixed `EPSILON` is wrong across magnitude scales: large
+/// coordinates differing in their last bit wrongly compare unequal, while
+/// values near zero wrongly compare equal. Implementors expose sensible
+/// defaults through [`default_epsilon`](ApproxEq::default_epsilon),
+/// [`default_max_relative`](ApproxEq::default_max_relative) and
+/// [`default_max_ulps`](ApproxEq::default_max_ulps), and callers that need a
+/// tighter or looser bound reach for [`relative_eq`](ApproxEq::relative_eq) or
+/// [`ulps_eq`](ApproxEq::ulps_eq) directly.
+pub trait ApproxEq {
+    /// The tolerance type used by the comparisons (the scalar itself for the
+    /// float primitives, the component scalar for aggregates).
+    type Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon;
+    fn default_max_relative() -> Self::Epsilon;
+    fn default_max_ulps() -> u32;
+
+    fn relative_eq(self, other: Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+    fn ulps_eq(self, other: Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    /// Compare at the implementor's default tolerance.
+    fn approx_eq(self, other: Self) -> bool
+        where Self: Sized
+    {
+        self.relative_eq(other, Self::default_epsilon(), Self::default_max_relative())
+    }
+}
+
+impl ApproxEq for f32 {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        EPSILON
+    }
+
+    fn default_max_relative() -> f32 {
+        EPSILON
+    }
+
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn relative_eq(self, other: f32, epsilon: f32, max_relative: f32) -> bool {
+        // Straddle-zero guard, then a relative bound scaled to the larger operand.
+        if (self - other).abs() <= epsilon {
+            return true;
+        }
+
+        let largest = self.abs().max(other.abs());
+        (self - other).abs() <= largest * max_relative
+    }
+
+    fn ulps_eq(self, other: f32, epsilon: f32, max_ulps: u32) -> bool {
+        // Absolute check first so values straddling zero still compare equal.
+        if (self - other).abs() <= epsilon {
+            return true;
+        }
+
+        let a_bits = self.to_bits() as i32;
+        let b_bits = other.to_bits() as i32;
+
+        // Differing sign bits never fall within an ULPS window.
+        if (a_bits < 0) != (b_bits < 0) {
+            return false;
+        }
+
+        (a_bits.wrapping_sub(b_bits)).wrapping_abs() as u32 <= max_ulps
+    }
+}
+
+impl ApproxEq for f64 {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        EPSILON as f64
+    }
+
+    fn default_max_relative() -> f64 {
+        EPSILON as f64
+    }
+
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn relative_eq(self, other: f64, epsilon: f64, max_relative: f64) -> bool {
+        if (self - other).abs() <= epsilon {
+            return true;
+        }
+
+        let largest = self.abs().max(other.abs());
+        (self - other).abs() <= largest * max_relative
+    }
+
+    fn ulps_eq(self, other: f64, epsilon: f64, max_ulps: u32) -> bool {
+        if (self - other).abs() <= epsilon {
+            return true;
+        }
+
+        let a_bits = self.to_bits() as i64;
+        let b_bits = other.to_bits() as i64;
+
+        if (a_bits < 0) != (b_bits < 0) {
+            return false;
+        }
+
+        (a_bits.wrapping_sub(b_bits)).wrapping_abs() as u64 <= max_ulps as u64
+    }
+}
+
+// Integer scalars compare exactly; the tolerance arguments are ignored.
+macro_rules! impl_approx_eq_int {
+    ($($t:ty),*) => {
+        $(
+            impl ApproxEq for $t {
+                type Epsilon = $t;
+
+                fn default_epsilon() -> $t {
+                    0
+                }
+
+                fn default_max_relative() -> $t {
+                    0
+                }
+
+                fn default_max_ulps() -> u32 {
+                    0
+                }
+
+                fn relative_eq(self, other: $t, _epsilon: $t, _max_relative: $t) -> bool {
+                    self == other
+                }
+
+                fn ulps_eq(self, other: $t, _epsilon: $t, _max_ulps: u32) -> bool {
+                    self == other
+                }
+            }
+        )*
+    }
+}
+
+impl_approx_eq_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_eq_scales_with_magnitude() {
+        // Two large values half a relative tolerance apart are "equal"; zero and
+        // ten epsilons are not, because the relative bound vanishes near zero.
+        let a = 1_000_000.0f32;
+        let b = a + (a * EPSILON * 0.5);
+        assert!(a.relative_eq(b, f32::default_epsilon(), f32::default_max_relative()));
+        assert!(!0.0f32.relative_eq(EPSILON * 10.0, f32::default_epsilon(), f32::default_max_relative()));
+    }
+
+    #[test]
+    fn ulps_eq_straddles_zero_via_absolute_check() {
+        // -0.0 and +0.0 have opposite sign bits but the absolute pre-check
+        // must still report them equal.
+        assert!((-0.0f32).ulps_eq(0.0, f32::default_epsilon(), 4));
+        // A tiny negative and tiny positive straddling zero are within epsilon.
+        assert!((-1e-7f32).ulps_eq(1e-7, f32::default_epsilon(), 4));
+    }
+
+    #[test]
+    fn ulps_eq_rejects_opposite_signs_outside_epsilon() {
+        assert!(!(1.0f32).ulps_eq(-1.0, f32::default_epsilon(), 4));
+    }
+
+    #[test]
+    fn ulps_eq_counts_representable_steps() {
+        let a = 1.0f32;
+        let three_up = f32::from_bits(a.to_bits() + 3);
+        let five_up = f32::from_bits(a.to_bits() + 5);
+        assert!(a.ulps_eq(three_up, 0.0, 4));
+        assert!(!a.ulps_eq(five_up, 0.0, 4));
+    }
+
+    #[test]
+    fn integer_scalars_compare_exactly() {
+        assert!(3i32.approx_eq(3));
+        assert!(!3i32.approx_eq(4));
+    }
+
+    #[test]
+    fn f64_is_supported() {
+        assert!(1.0f64.approx_eq(1.0));
+        assert!(!1.0f64.approx_eq(2.0));
+    }
+}
@@ -0,0 +1,2 @@
+/// Tolerance used by the vector/quaternion length and equality checks.
+pub const EPSILON: f32 = 1e-5;
@@ -0,0 +1,19 @@
+extern crate num_traits;
+
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(feature = "byteorder")]
+extern crate byteorder;
+
+pub mod consts;
+pub mod approx;
+pub mod vector3;
+pub mod vec3a;
+pub mod quaternion;
+
+pub use approx::ApproxEq;
+pub use vector3::{ UnknownUnit, Vec3, Vector3 };
+pub use vec3a::Vec3A;
+pub use quaternion::Quaternion;
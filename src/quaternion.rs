@@ -0,0 +1,319 @@
+use std::ops::{ Mul, MulAssign, Neg };
+use std::fmt;
+
+use consts::{ EPSILON };
+use vector3::{ Vector3, Vec3 };
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32
+}
+
+impl Quaternion {
+    pub const IDENTITY: Quaternion = Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Quaternion {
+        Quaternion {
+            x: x,
+            y: y,
+            z: z,
+            w: w
+        }
+    }
+
+    pub fn identity() -> Quaternion {
+        Quaternion::IDENTITY
+    }
+
+    pub fn from_axis_angle(axis: Vec3, radians: f32) -> Quaternion {
+        let half = radians * 0.5;
+        let s = half.sin();
+        let axis = axis.normalized();
+
+        Quaternion {
+            x: axis.x * s,
+            y: axis.y * s,
+            z: axis.z * s,
+            w: half.cos()
+        }
+    }
+
+    pub fn from_euler(x: f32, y: f32, z: f32) -> Quaternion {
+        let (sx, cx) = (x * 0.5).sin_cos();
+        let (sy, cy) = (y * 0.5).sin_cos();
+        let (sz, cz) = (z * 0.5).sin_cos();
+
+        Quaternion {
+            x: sx * cy * cz - cx * sy * sz,
+            y: cx * sy * cz + sx * cy * sz,
+            z: cx * cy * sz - sx * sy * cz,
+            w: cx * cy * cz + sx * sy * sz
+        }
+    }
+
+    pub fn sqr_magnitude(&self) -> f32 {
+        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        self.sqr_magnitude().sqrt()
+    }
+
+    pub fn normalize(&mut self) {
+        let mag = self.magnitude();
+        if mag > EPSILON {
+            let inv = 1.0 / mag;
+            self.x *= inv;
+            self.y *= inv;
+            self.z *= inv;
+            self.w *= inv;
+        }
+        else {
+            *self = Quaternion::IDENTITY;
+        }
+    }
+
+    pub fn normalized(&self) -> Quaternion {
+        let mut q = *self;
+        q.normalize();
+        q
+    }
+
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w
+        }
+    }
+
+    pub fn inverse(&self) -> Quaternion {
+        let sqr = self.sqr_magnitude();
+        if sqr < EPSILON {
+            return Quaternion::IDENTITY;
+        }
+
+        let inv = 1.0 / sqr;
+        let c = self.conjugate();
+        Quaternion {
+            x: c.x * inv,
+            y: c.y * inv,
+            z: c.z * inv,
+            w: c.w * inv
+        }
+    }
+
+    pub fn dot(a: Quaternion, b: Quaternion) -> f32 {
+        a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+    }
+
+    pub fn mul(a: Quaternion, b: Quaternion) -> Quaternion {
+        Quaternion {
+            x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            y: a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            z: a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+            w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z
+        }
+    }
+
+    pub fn rotate(&self, v: Vec3) -> Vec3 {
+        let u = Vector3::new(self.x, self.y, self.z);
+        let uv = Vector3::cross(u, v);
+        let uuv = Vector3::cross(u, uv);
+
+        v + uv * (2.0 * self.w) + uuv * 2.0
+    }
+
+    pub fn slerp(a: Quaternion, b: Quaternion, t: f32) -> Quaternion {
+        let a = a.normalized();
+        let mut b = b.normalized();
+
+        let mut d = Quaternion::dot(a, b);
+        if d < 0.0 {
+            b = -b;
+            d = -d;
+        }
+
+        if d > 0.9995 {
+            let mut result = Quaternion {
+                x: a.x + (b.x - a.x) * t,
+                y: a.y + (b.y - a.y) * t,
+                z: a.z + (b.z - a.z) * t,
+                w: a.w + (b.w - a.w) * t
+            };
+            result.normalize();
+            return result;
+        }
+
+        let theta = d.acos();
+        let sin_theta = theta.sin();
+        let wa = ((1.0 - t) * theta).sin() / sin_theta;
+        let wb = (t * theta).sin() / sin_theta;
+
+        Quaternion {
+            x: a.x * wa + b.x * wb,
+            y: a.y * wa + b.y * wb,
+            z: a.z * wa + b.z * wb,
+            w: a.w * wa + b.w * wb
+        }
+    }
+
+    pub fn look_rotation(forward: Vec3, up: Vec3) -> Quaternion {
+        let mut f = forward;
+        let mut u = up;
+        Vector3::ortho_normalize(&mut f, &mut u);
+        let r = Vector3::cross(u, f);
+
+        // Build the rotation from the orthonormal basis (right, up, forward).
+        let trace = r.x + u.y + f.z;
+        if trace > 0.0 {
+            let s = 0.5 / (trace + 1.0).sqrt();
+            Quaternion {
+                x: (u.z - f.y) * s,
+                y: (f.x - r.z) * s,
+                z: (r.y - u.x) * s,
+                w: 0.25 / s
+            }
+        }
+        else if r.x > u.y && r.x > f.z {
+            let s = 2.0 * (1.0 + r.x - u.y - f.z).sqrt();
+            Quaternion {
+                x: 0.25 * s,
+                y: (u.x + r.y) / s,
+                z: (f.x + r.z) / s,
+                w: (u.z - f.y) / s
+            }
+        }
+        else if u.y > f.z {
+            let s = 2.0 * (1.0 + u.y - r.x - f.z).sqrt();
+            Quaternion {
+                x: (u.x + r.y) / s,
+                y: 0.25 * s,
+                z: (f.y + u.z) / s,
+                w: (f.x - r.z) / s
+            }
+        }
+        else {
+            let s = 2.0 * (1.0 + f.z - r.x - u.y).sqrt();
+            Quaternion {
+                x: (f.x + r.z) / s,
+                y: (f.y + u.z) / s,
+                z: 0.25 * s,
+                w: (r.y - u.x) / s
+            }
+        }
+    }
+}
+
+// Formatting
+impl fmt::Debug for Quaternion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+// Ops
+impl Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+    fn mul(self, other: Quaternion) -> Quaternion {
+        Quaternion::mul(self, other)
+    }
+}
+
+impl Mul<Vec3> for Quaternion {
+    type Output = Vec3;
+    fn mul(self, other: Vec3) -> Vec3 {
+        self.rotate(other)
+    }
+}
+
+impl MulAssign<Quaternion> for Quaternion {
+    fn mul_assign(&mut self, other: Quaternion) {
+        *self = Quaternion::mul(*self, other);
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+    fn neg(self) -> Quaternion {
+        Quaternion {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    fn vec_close(a: Vec3, b: Vec3) -> bool {
+        close(a.x, b.x) && close(a.y, b.y) && close(a.z, b.z)
+    }
+
+    #[test]
+    fn identity_rotation_is_a_noop() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert!(vec_close(Quaternion::identity().rotate(v), v));
+    }
+
+    #[test]
+    fn axis_angle_rotates_about_the_axis() {
+        // A half turn about +Z maps +X onto -X.
+        let q = Quaternion::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), PI);
+        assert!(vec_close(q.rotate(Vec3::new(1.0, 0.0, 0.0)), Vec3::new(-1.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn inverse_undoes_the_rotation() {
+        let q = Quaternion::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 0.73);
+        let round_trip = q.inverse().rotate(q.rotate(Vec3::new(0.4, -1.2, 2.0)));
+        assert!(vec_close(round_trip, Vec3::new(0.4, -1.2, 2.0)));
+    }
+
+    #[test]
+    fn slerp_hits_its_endpoints() {
+        let a = Quaternion::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 0.2);
+        let b = Quaternion::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 1.3);
+        let start = Quaternion::slerp(a, b, 0.0);
+        let end = Quaternion::slerp(a, b, 1.0);
+        assert!(close(Quaternion::dot(start, a).abs(), 1.0));
+        assert!(close(Quaternion::dot(end, b).abs(), 1.0));
+    }
+
+    #[test]
+    fn slerp_takes_the_short_path_for_antiparallel_inputs() {
+        // Opposite-sign quaternions represent the same rotation; slerp must
+        // negate to the short arc and never produce NaNs.
+        let a = Quaternion::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 0.5);
+        let b = -a;
+        let mid = Quaternion::slerp(a, b, 0.5);
+        assert!(mid.x.is_finite() && mid.w.is_finite());
+        assert!(close(mid.magnitude(), 1.0));
+    }
+
+    #[test]
+    fn look_rotation_aims_forward_along_the_target() {
+        let forward = Vec3::new(0.0, 0.0, 1.0);
+        let q = Quaternion::look_rotation(forward, Vec3::new(0.0, 1.0, 0.0));
+        assert!(vec_close(q.rotate(Vec3::new(0.0, 0.0, 1.0)), forward));
+    }
+}
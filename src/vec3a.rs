@@ -0,0 +1,355 @@
+use std::ops::{ Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign };
+use std::fmt;
+
+use consts::{ EPSILON };
+use vector3::Vec3;
+
+/// A 16-byte aligned, 4-lane companion to [`Vec3`](vector3::Vec3).
+///
+/// The extra padding lane lets hot loops (particle integration, broadphase
+/// distance checks) load and store the vector with a single aligned SIMD move.
+/// The public surface covers the scalar vector's core (`dot`, `cross`,
+/// `magnitude`, `normalize`, `lerp` and the arithmetic operators); the
+/// element-wise binary operators are dispatched to SSE / `wasm32` intrinsics
+/// where available and fall back to the obvious scalar code everywhere else,
+/// so it still builds on every target.
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+pub struct Vec3A {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    // The fourth lane pads to 16 bytes; it is always 0.0 on construction and
+    // is dropped from every operator result.
+    w: f32
+}
+
+impl Vec3A {
+    pub const ZERO: Vec3A = Vec3A::new(0.0, 0.0, 0.0);
+    pub const ONE: Vec3A = Vec3A::new(1.0, 1.0, 1.0);
+    pub const X: Vec3A = Vec3A::new(1.0, 0.0, 0.0);
+    pub const Y: Vec3A = Vec3A::new(0.0, 1.0, 0.0);
+    pub const Z: Vec3A = Vec3A::new(0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32) -> Vec3A {
+        Vec3A {
+            x: x,
+            y: y,
+            z: z,
+            w: 0.0
+        }
+    }
+
+    pub fn dot(a: Vec3A, b: Vec3A) -> f32 {
+        a.x * b.x + a.y * b.y + a.z * b.z
+    }
+
+    pub fn cross(a: Vec3A, b: Vec3A) -> Vec3A {
+        Vec3A::new(
+            a.y * b.z - a.z * b.y,
+            a.z * b.x - a.x * b.z,
+            a.x * b.y - a.y * b.x
+        )
+    }
+
+    pub fn sqr_magnitude(&self) -> f32 {
+        Vec3A::dot(*self, *self)
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        self.sqr_magnitude().sqrt()
+    }
+
+    pub fn normalize(&mut self) {
+        let mag = self.magnitude();
+        if mag > EPSILON {
+            *self = *self / mag;
+        }
+        else {
+            *self = Vec3A::ZERO;
+        }
+    }
+
+    pub fn normalized(&self) -> Vec3A {
+        let mut v = *self;
+        v.normalize();
+        v
+    }
+
+    pub fn lerp(start: Vec3A, end: Vec3A, t: f32) -> Vec3A {
+        let alpha = if t < 0.0 { 0.0 } else if t > 1.0 { 1.0 } else { t };
+        start + (end - start) * alpha
+    }
+}
+
+// SIMD dispatch for the element-wise binary operators. Each `lanes_*` helper
+// takes the two operands as four-element arrays (x, y, z, pad) and returns the
+// result in the same layout.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse"))]
+mod simd {
+    use std::arch::x86_64::*;
+
+    #[inline]
+    unsafe fn load(v: [f32; 4]) -> __m128 {
+        _mm_set_ps(v[3], v[2], v[1], v[0])
+    }
+
+    #[inline]
+    unsafe fn store(v: __m128) -> [f32; 4] {
+        let mut out = [0.0f32; 4];
+        _mm_storeu_ps(out.as_mut_ptr(), v);
+        out
+    }
+
+    #[inline]
+    pub fn add(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        unsafe { store(_mm_add_ps(load(a), load(b))) }
+    }
+
+    #[inline]
+    pub fn sub(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        unsafe { store(_mm_sub_ps(load(a), load(b))) }
+    }
+
+    #[inline]
+    pub fn mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        unsafe { store(_mm_mul_ps(load(a), load(b))) }
+    }
+
+    #[inline]
+    pub fn div(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        unsafe { store(_mm_div_ps(load(a), load(b))) }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod simd {
+    use std::arch::wasm32::*;
+
+    #[inline]
+    unsafe fn load(v: [f32; 4]) -> v128 {
+        v128_load(v.as_ptr() as *const v128)
+    }
+
+    #[inline]
+    unsafe fn store(v: v128) -> [f32; 4] {
+        let mut out = [0.0f32; 4];
+        v128_store(out.as_mut_ptr() as *mut v128, v);
+        out
+    }
+
+    #[inline]
+    pub fn add(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        unsafe { store(f32x4_add(load(a), load(b))) }
+    }
+
+    #[inline]
+    pub fn sub(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        unsafe { store(f32x4_sub(load(a), load(b))) }
+    }
+
+    #[inline]
+    pub fn mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        unsafe { store(f32x4_mul(load(a), load(b))) }
+    }
+
+    #[inline]
+    pub fn div(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        unsafe { store(f32x4_div(load(a), load(b))) }
+    }
+}
+
+// Portable fallback for targets without a supported vector unit.
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "sse"),
+    all(target_arch = "wasm32", target_feature = "simd128")
+)))]
+mod simd {
+    #[inline]
+    pub fn add(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        [a[0] + b[0], a[1] + b[1], a[2] + b[2], a[3] + b[3]]
+    }
+
+    #[inline]
+    pub fn sub(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        [a[0] - b[0], a[1] - b[1], a[2] - b[2], a[3] - b[3]]
+    }
+
+    #[inline]
+    pub fn mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        [a[0] * b[0], a[1] * b[1], a[2] * b[2], a[3] * b[3]]
+    }
+
+    #[inline]
+    pub fn div(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        [a[0] / b[0], a[1] / b[1], a[2] / b[2], a[3] / b[3]]
+    }
+}
+
+impl Vec3A {
+    #[inline]
+    fn to_lanes(self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    #[inline]
+    fn from_lanes(lanes: [f32; 4]) -> Vec3A {
+        Vec3A {
+            x: lanes[0],
+            y: lanes[1],
+            z: lanes[2],
+            w: 0.0
+        }
+    }
+}
+
+// Conversions so callers can promote `Vector3` storage into aligned lanes,
+// run a vectorized pass, and convert back.
+impl From<Vec3> for Vec3A {
+    fn from(v: Vec3) -> Vec3A {
+        Vec3A::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3A> for Vec3 {
+    fn from(v: Vec3A) -> Vec3 {
+        Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+// Formatting
+impl fmt::Debug for Vec3A {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl fmt::Display for Vec3A {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+// Ops
+impl Add<Vec3A> for Vec3A {
+    type Output = Vec3A;
+    fn add(self, other: Vec3A) -> Vec3A {
+        Vec3A::from_lanes(simd::add(self.to_lanes(), other.to_lanes()))
+    }
+}
+
+impl Sub<Vec3A> for Vec3A {
+    type Output = Vec3A;
+    fn sub(self, other: Vec3A) -> Vec3A {
+        Vec3A::from_lanes(simd::sub(self.to_lanes(), other.to_lanes()))
+    }
+}
+
+impl Mul<Vec3A> for Vec3A {
+    type Output = Vec3A;
+    fn mul(self, other: Vec3A) -> Vec3A {
+        Vec3A::from_lanes(simd::mul(self.to_lanes(), other.to_lanes()))
+    }
+}
+
+impl Mul<f32> for Vec3A {
+    type Output = Vec3A;
+    fn mul(self, other: f32) -> Vec3A {
+        Vec3A::from_lanes(simd::mul(self.to_lanes(), [other, other, other, other]))
+    }
+}
+
+impl Mul<Vec3A> for f32 {
+    type Output = Vec3A;
+    fn mul(self, other: Vec3A) -> Vec3A {
+        other * self
+    }
+}
+
+impl Div<f32> for Vec3A {
+    type Output = Vec3A;
+    fn div(self, other: f32) -> Vec3A {
+        Vec3A::from_lanes(simd::div(self.to_lanes(), [other, other, other, other]))
+    }
+}
+
+impl Neg for Vec3A {
+    type Output = Vec3A;
+    fn neg(self) -> Vec3A {
+        Vec3A::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl AddAssign<Vec3A> for Vec3A {
+    fn add_assign(&mut self, other: Vec3A) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<Vec3A> for Vec3A {
+    fn sub_assign(&mut self, other: Vec3A) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<f32> for Vec3A {
+    fn mul_assign(&mut self, other: f32) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign<f32> for Vec3A {
+    fn div_assign(&mut self, other: f32) {
+        *self = *self / other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-5
+    }
+
+    #[test]
+    fn arithmetic_matches_scalar_expectations() {
+        let a = Vec3A::new(1.0, 2.0, 3.0);
+        let b = Vec3A::new(4.0, -1.0, 0.5);
+        let sum = a + b;
+        let diff = a - b;
+        let prod = a * b;
+        assert!(close(sum.x, 5.0) && close(sum.y, 1.0) && close(sum.z, 3.5));
+        assert!(close(diff.x, -3.0) && close(diff.y, 3.0) && close(diff.z, 2.5));
+        assert!(close(prod.x, 4.0) && close(prod.y, -2.0) && close(prod.z, 1.5));
+    }
+
+    #[test]
+    fn dot_and_cross_agree_with_definition() {
+        let a = Vec3A::X;
+        let b = Vec3A::Y;
+        assert!(close(Vec3A::dot(a, b), 0.0));
+        let c = Vec3A::cross(a, b);
+        assert!(close(c.x, 0.0) && close(c.y, 0.0) && close(c.z, 1.0));
+    }
+
+    #[test]
+    fn normalize_yields_unit_length() {
+        let v = Vec3A::new(3.0, 0.0, 4.0).normalized();
+        assert!(close(v.magnitude(), 1.0));
+    }
+
+    #[test]
+    fn round_trips_through_vec3() {
+        let original = Vec3::new(1.5, -2.0, 7.25);
+        let promoted = Vec3A::from(original);
+        let back: Vec3 = promoted.into();
+        assert!(close(back.x, 1.5) && close(back.y, -2.0) && close(back.z, 7.25));
+    }
+
+    #[test]
+    fn const_constructors_are_usable() {
+        const V: Vec3A = Vec3A::new(1.0, 2.0, 3.0);
+        assert!(close(V.x, 1.0) && close(Vec3A::ZERO.x, 0.0) && close(Vec3A::ONE.z, 1.0));
+    }
+}
@@ -1,300 +1,679 @@
-use std::ops::{ Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign };
+use std::ops::{ Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign, Index, IndexMut };
 use std::cmp::{ PartialEq, Eq };
+use std::marker::PhantomData;
 use std::fmt;
 
-use {ApproxEq, Clamp, Clamp01};
+use num_traits::{ Float, Zero };
+
+use ApproxEq;
 use consts::{ EPSILON };
 
+#[cfg(feature = "byteorder")]
+use std::io::{ self, Read, Write };
+#[cfg(feature = "byteorder")]
+use byteorder::{ BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt };
+
+/// Marker for a vector whose coordinate space is unspecified.
+///
+/// This is the default unit for [`Vec3`] and keeps the historical `f32` API
+/// source-compatible. Callers who want the type system to reject mixing
+/// coordinate spaces substitute their own zero-size marker for `U`.
+pub struct UnknownUnit;
+
 #[repr(C)]
-#[derive(Clone, Copy)]
-pub struct Vector3 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32
+pub struct Vector3<T, U = UnknownUnit> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    _unit: PhantomData<U>
+}
+
+/// The original, unit-less single-precision vector.
+pub type Vec3 = Vector3<f32, UnknownUnit>;
+
+// `derive` would needlessly bound `U: Clone`/`U: Copy`, so implement the
+// marker-agnostic traits by hand.
+impl<T: Clone, U> Clone for Vector3<T, U> {
+    fn clone(&self) -> Vector3<T, U> {
+        Vector3 {
+            x: self.x.clone(),
+            y: self.y.clone(),
+            z: self.z.clone(),
+            _unit: PhantomData
+        }
+    }
 }
 
-impl Vector3 {
-    pub const ZERO: Vector3 = Vector3{ x: 0.0, y: 0.0, z: 0.0 };
-    pub const ONE: Vector3 = Vector3 { x: 1.0, y: 1.0, z: 1.0 };
-    pub const FORWARD: Vector3 = Vector3 { x: 0.0, y: 0.0, z: 1.0 };
-    pub const RIGHT: Vector3 = Vector3 { x: 1.0, y: 0.0, z: 0.0 };
-    pub const UP: Vector3 = Vector3 { x: 0.0, y: 1.0, z: 0.0 };
+impl<T: Copy, U> Copy for Vector3<T, U> {}
 
-    pub fn new(x: f32, y: f32, z: f32) -> Vector3 {
+impl<T, U> Vector3<T, U> {
+    pub fn new(x: T, y: T, z: T) -> Vector3<T, U> {
         Vector3 {
             x: x,
             y: y,
-            z: z
+            z: z,
+            _unit: PhantomData
         }
     }
-    
-    pub fn clamp_magnitude(&self, max_length: f32) -> Vector3 {
+}
+
+// Constructors that only make sense for the numeric identities.
+impl<T: Zero, U> Vector3<T, U> {
+    pub fn zero() -> Vector3<T, U> {
+        Vector3::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+// Preserve the historical `f32` constant surface on the default alias so
+// existing call sites keep compiling unchanged.
+impl Vec3 {
+    pub const ZERO: Vec3 = Vector3 { x: 0.0, y: 0.0, z: 0.0, _unit: PhantomData };
+    pub const ONE: Vec3 = Vector3 { x: 1.0, y: 1.0, z: 1.0, _unit: PhantomData };
+    pub const FORWARD: Vec3 = Vector3 { x: 0.0, y: 0.0, z: 1.0, _unit: PhantomData };
+    pub const RIGHT: Vec3 = Vector3 { x: 1.0, y: 0.0, z: 0.0, _unit: PhantomData };
+    pub const UP: Vec3 = Vector3 { x: 0.0, y: 1.0, z: 0.0, _unit: PhantomData };
+}
+
+// Methods available for any numeric scalar (physics f64, tile/grid i32, ...).
+impl<T, U> Vector3<T, U>
+    where T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>
+{
+    pub fn sqr_magnitude(&self) -> T {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    pub fn dot(a: Vector3<T, U>, b: Vector3<T, U>) -> T {
+        a.x * b.x + a.y * b.y + a.z * b.z
+    }
+
+    pub fn cross(a: Vector3<T, U>, b: Vector3<T, U>) -> Vector3<T, U> {
+        Vector3::new(
+            a.y * b.z - a.z * b.y,
+            a.z * b.x - a.x * b.z,
+            a.x * b.y - a.y * b.x
+        )
+    }
+
+    pub fn scale(v: Vector3<T, U>, other: Vector3<T, U>) -> Vector3<T, U> {
+        Vector3::new(v.x * other.x, v.y * other.y, v.z * other.z)
+    }
+}
+
+// `abs` needs sign, so it is gated on `Neg`/`Zero` (excluding the unsigned
+// integers, for which it would be meaningless).
+impl<T, U> Vector3<T, U>
+    where T: Copy + PartialOrd + Neg<Output = T> + Zero
+{
+    pub fn abs(&self) -> Vector3<T, U> {
+        let abs = |v: T| if v < T::zero() { -v } else { v };
+        Vector3::new(abs(self.x), abs(self.y), abs(self.z))
+    }
+}
+
+// Component selection needs only ordering, so it is available on every numeric
+// scalar including the unsigned-integer grid vectors.
+impl<T, U> Vector3<T, U>
+    where T: Copy + PartialOrd
+{
+    pub fn min(a: Vector3<T, U>, b: Vector3<T, U>) -> Vector3<T, U> {
+        let min = |x: T, y: T| if x < y { x } else { y };
+        Vector3::new(min(a.x, b.x), min(a.y, b.y), min(a.z, b.z))
+    }
+
+    pub fn max(a: Vector3<T, U>, b: Vector3<T, U>) -> Vector3<T, U> {
+        let max = |x: T, y: T| if x > y { x } else { y };
+        Vector3::new(max(a.x, b.x), max(a.y, b.y), max(a.z, b.z))
+    }
+
+    pub fn clamp(&self, min: Vector3<T, U>, max: Vector3<T, U>) -> Vector3<T, U> {
+        let clamp = |v: T, lo: T, hi: T| if v < lo { lo } else if v > hi { hi } else { v };
+        Vector3::new(
+            clamp(self.x, min.x, max.x),
+            clamp(self.y, min.y, max.y),
+            clamp(self.z, min.z, max.z)
+        )
+    }
+}
+
+// Methods requiring a real-valued scalar.
+impl<T: Float, U> Vector3<T, U> {
+    pub fn clamp_magnitude(&self, max_length: T) -> Vector3<T, U> {
         if self.sqr_magnitude() > max_length * max_length {
             return self.normalized() * max_length
         }
-        
-        *self
-    }
 
-    pub fn sqr_magnitude(&self) -> f32 {
-        self.x * self.x + self.y * self.y + self.z * self.z
+        *self
     }
 
-    pub fn magnitude(&self) -> f32 {
-        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    pub fn magnitude(&self) -> T {
+        self.sqr_magnitude().sqrt()
     }
 
     pub fn normalize(&mut self) {
         let mag = self.magnitude();
-        if mag > EPSILON {
+        if mag > T::from(EPSILON).unwrap() {
             *self = *self / mag;
         }
         else {
-            *self = Vector3::ZERO;
+            *self = Vector3::zero();
         }
     }
 
-    pub fn normalized(&self) -> Vector3 {
+    pub fn normalized(&self) -> Vector3<T, U> {
         let mag = self.magnitude();
-        if mag > EPSILON {
+        if mag > T::from(EPSILON).unwrap() {
             return *self / mag;
         }
-        
-        Vector3::ZERO
-    }
 
-    pub fn dot(a: Vector3, b: Vector3) -> f32 {
-        a.x * b.x + a.y * b.y + a.z * b.z
+        Vector3::zero()
     }
 
-    pub fn cross(a: Vector3, b: Vector3) -> Vector3 {
-        Vector3 {
-            x: a.y * b.z - a.z * b.y,
-            y: a.z * b.x - a.x * b.z,
-            z: a.x * b.y - a.y * b.x
-        }
-    }
-    
-    pub fn distance(a: Vector3, b: Vector3) -> f32 {
+    pub fn distance(a: Vector3<T, U>, b: Vector3<T, U>) -> T {
         (a - b).magnitude()
     }
 
-    pub fn angle(a: Vector3, b: Vector3) -> f32 {
+    pub fn angle(a: Vector3<T, U>, b: Vector3<T, U>) -> T {
         Vector3::dot(a.normalized(), b.normalized())
-            .clamp(-1.0, 1.0)
+            .max(-T::one())
+            .min(T::one())
             .acos()
     }
 
-    pub fn scale(v: Vector3, other: Vector3) -> Vector3 {
-        Vector3 {
-            x: v.x * other.x,
-            y: v.y * other.y,
-            z: v.z * other.z
-        }
-    }
-    
-    pub fn ortho_normalize(a: &mut Vector3, b: &mut Vector3) {
+    pub fn ortho_normalize(a: &mut Vector3<T, U>, b: &mut Vector3<T, U>) {
         a.normalize();
 
-        let mut c = Vector3::cross(*a, *b);
-        c.normalize();
+        // Gram-Schmidt: strip the component of `b` along `a`, then normalize.
+        *b = (*b - Vector3::project(*b, *a)).normalized();
+    }
 
-        *b = Vector3::cross(*a, *b);
-        b.normalize();
+    pub fn ortho_normalize3(a: &mut Vector3<T, U>, b: &mut Vector3<T, U>, c: &mut Vector3<T, U>) {
+        Vector3::ortho_normalize(a, b);
+        *c = Vector3::cross(*a, *b);
     }
 
-    
-    pub fn lerp(start: Vector3, end: Vector3, t: f32) -> Vector3 {
-        let alpha = t.clamp01();
+    pub fn lerp(start: Vector3<T, U>, end: Vector3<T, U>, t: T) -> Vector3<T, U> {
+        let alpha = if t < T::zero() { T::zero() } else if t > T::one() { T::one() } else { t };
 
-        Vector3 {
-            x: start.x + (end.x - start.x) * alpha,
-            y: start.y + (end.y - start.y) * alpha,
-            z: start.z + (end.z - start.z) * alpha
-        }
+        Vector3::new(
+            start.x + (end.x - start.x) * alpha,
+            start.y + (end.y - start.y) * alpha,
+            start.z + (end.z - start.z) * alpha
+        )
     }
 
-    pub fn lerp_unclamped(start: Vector3, end: Vector3, t: f32) -> Vector3 {
-        Vector3 {
-            x: start.x + (end.x - start.x) * t,
-            y: start.y + (end.y - start.y) * t,
-            z: start.z + (end.z - start.z) * t
-        }
+    pub fn lerp_unclamped(start: Vector3<T, U>, end: Vector3<T, U>, t: T) -> Vector3<T, U> {
+        Vector3::new(
+            start.x + (end.x - start.x) * t,
+            start.y + (end.y - start.y) * t,
+            start.z + (end.z - start.z) * t
+        )
     }
 
-    pub fn project(v: Vector3, normal: Vector3) -> Vector3 {
+    pub fn project(v: Vector3<T, U>, normal: Vector3<T, U>) -> Vector3<T, U> {
         let dot = Vector3::dot(normal, normal);
-        if dot < EPSILON {
-            Vector3::ZERO
+        if dot < T::from(EPSILON).unwrap() {
+            Vector3::zero()
         }
         else {
-            normal * Vector3::dot(v, normal) / dot
+            normal * (Vector3::dot(v, normal) / dot)
         }
     }
-    
-    pub fn project_on_segment(point: Vector3, start: Vector3, end: Vector3) -> Vector3 {
+
+    pub fn project_on_segment(point: Vector3<T, U>, start: Vector3<T, U>, end: Vector3<T, U>) -> Vector3<T, U> {
         let segment = end - start;
         let proj_point = Vector3::project(point, segment.normalized());
-        
+
         (proj_point - start).clamp_magnitude(segment.magnitude())
     }
 
-    pub fn project_on_plane(v: Vector3, normal: Vector3) -> Vector3 {
+    pub fn project_on_plane(v: Vector3<T, U>, normal: Vector3<T, U>) -> Vector3<T, U> {
         v - Vector3::project(v, normal)
     }
 
-    pub fn reflect(v: Vector3, normal: Vector3) -> Vector3 {
-        -2.0 * Vector3::dot(normal, v) * normal + v
+    pub fn reflect(v: Vector3<T, U>, normal: Vector3<T, U>) -> Vector3<T, U> {
+        normal * (Vector3::dot(normal, v) * (-T::one() - T::one())) + v
+    }
+
+    pub fn floor(&self) -> Vector3<T, U> {
+        Vector3::new(self.x.floor(), self.y.floor(), self.z.floor())
+    }
+
+    pub fn ceil(&self) -> Vector3<T, U> {
+        Vector3::new(self.x.ceil(), self.y.ceil(), self.z.ceil())
+    }
+
+    pub fn round(&self) -> Vector3<T, U> {
+        Vector3::new(self.x.round(), self.y.round(), self.z.round())
+    }
+
+    pub fn move_towards(current: Vector3<T, U>, target: Vector3<T, U>, max_delta: T) -> Vector3<T, U> {
+        let to_vector = target - current;
+        let dist = to_vector.magnitude();
+        if dist <= max_delta || dist < T::from(EPSILON).unwrap() {
+            return target;
+        }
+
+        current + to_vector / dist * max_delta
+    }
+
+    pub fn slerp(a: Vector3<T, U>, b: Vector3<T, U>, t: T) -> Vector3<T, U> {
+        let mag_a = a.magnitude();
+        let mag_b = b.magnitude();
+        if mag_a < T::from(EPSILON).unwrap() || mag_b < T::from(EPSILON).unwrap() {
+            return Vector3::lerp(a, b, t);
+        }
+
+        let dir_a = a / mag_a;
+        let dir_b = b / mag_b;
+        let d = Vector3::dot(dir_a, dir_b).max(-T::one()).min(T::one());
+        let theta = d.acos() * t;
+        let relative = (dir_b - dir_a * d).normalized();
+
+        (dir_a * theta.cos() + relative * theta.sin()) * (mag_a + (mag_b - mag_a) * t)
+    }
+
+    pub fn smooth_damp(
+        current: Vector3<T, U>,
+        target: Vector3<T, U>,
+        velocity: &mut Vector3<T, U>,
+        smooth_time: T,
+        max_speed: T,
+        dt: T
+    ) -> Vector3<T, U> {
+        let smooth_time = smooth_time.max(T::from(0.0001).unwrap());
+        let omega = T::from(2.0).unwrap() / smooth_time;
+
+        let x = omega * dt;
+        let e = T::one()
+            / (T::one()
+                + x
+                + T::from(0.48).unwrap() * x * x
+                + T::from(0.235).unwrap() * x * x * x);
+
+        let orig_target = target;
+        let max_change = max_speed * smooth_time;
+        let change = (current - target).clamp_magnitude(max_change);
+        let target = current - change;
+
+        let temp = (*velocity + change * omega) * dt;
+        *velocity = (*velocity - temp * omega) * e;
+        let mut output = target + (change + temp) * e;
+
+        // Snap to the goal instead of overshooting past it.
+        if Vector3::dot(orig_target - current, output - orig_target) > T::zero() {
+            output = orig_target;
+            *velocity = Vector3::zero();
+        }
+
+        output
+    }
+}
+
+// Component access (0 -> x, 1 -> y, 2 -> z).
+impl<T, U> Index<usize> for Vector3<T, U> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index out of range for Vector3: {}", index)
+        }
+    }
+}
+
+impl<T, U> IndexMut<usize> for Vector3<T, U> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("index out of range for Vector3: {}", index)
+        }
     }
 }
 
 // Formatting
-impl fmt::Debug for Vector3 {
+impl<T: fmt::Debug, U> fmt::Debug for Vector3<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+        write!(f, "({:?}, {:?}, {:?})", self.x, self.y, self.z)
     }
 }
 
-impl fmt::Display for Vector3 {
+impl<T: fmt::Display, U> fmt::Display for Vector3<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {}, {})", self.x, self.y, self.z)
     }
 }
 
-// Equality
-impl PartialEq for Vector3 {
-    fn eq(&self, other: &Vector3) -> bool {
-        self.x.approx_eq(other.x) && self.y.approx_eq(other.y) && self.z.approx_eq(other.z)
+// Equality. `==` delegates to each scalar's default-tolerance `ApproxEq` path,
+// preserving the baseline's approximate float comparison. Because `ApproxEq`
+// is implemented exactly for the integer scalars, this also gives the i32 grid
+// and f64 physics vectors a working `==` (exact for integers, tolerant for
+// floats). Callers needing a specific tolerance use the `ApproxEq` methods.
+impl<T: ApproxEq + Copy, U> PartialEq for Vector3<T, U>
+    where T::Epsilon: Copy
+{
+    fn eq(&self, other: &Vector3<T, U>) -> bool {
+        self.approx_eq(*other)
     }
 }
 
-impl Eq for Vector3 {}
+impl<T: ApproxEq + Copy, U> Eq for Vector3<T, U>
+    where T::Epsilon: Copy
+{}
+
+impl<T: ApproxEq + Copy, U> ApproxEq for Vector3<T, U>
+    where T::Epsilon: Copy
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn relative_eq(self, other: Vector3<T, U>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.x.relative_eq(other.x, epsilon, max_relative)
+            && self.y.relative_eq(other.y, epsilon, max_relative)
+            && self.z.relative_eq(other.z, epsilon, max_relative)
+    }
 
-impl_op! { ApproxEq,
-    fn approx_eq(self: Vector3, other: Vector3) -> bool {
-        self.x.approx_eq(other.x) && self.y.approx_eq(other.y) && self.z.approx_eq(other.z)
+    fn ulps_eq(self, other: Vector3<T, U>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.x.ulps_eq(other.x, epsilon, max_ulps)
+            && self.y.ulps_eq(other.y, epsilon, max_ulps)
+            && self.z.ulps_eq(other.z, epsilon, max_ulps)
     }
 }
 
 // Ops
-impl_op! { Add,
-    fn add(self: Vector3, other: Vector3) -> Vector3 {
-        Vector3 {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z
-        }
+impl<T: Add<Output = T>, U> Add<Vector3<T, U>> for Vector3<T, U> {
+    type Output = Vector3<T, U>;
+    fn add(self, other: Vector3<T, U>) -> Vector3<T, U> {
+        Vector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
     }
 }
 
-impl_op! { Add,
-    fn add(self: Vector3, other: f32) -> Vector3 {
-        Vector3 {
-            x: self.x + other,
-            y: self.y + other,
-            z: self.z + other
-        }
+impl<T: Add<Output = T> + Copy, U> Add<T> for Vector3<T, U> {
+    type Output = Vector3<T, U>;
+    fn add(self, other: T) -> Vector3<T, U> {
+        Vector3::new(self.x + other, self.y + other, self.z + other)
     }
 }
 
-impl_op! { Sub,
-    fn sub(self: Vector3, other: Vector3) -> Vector3 {
-        Vector3 {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z
-        }
+impl<T: Sub<Output = T>, U> Sub<Vector3<T, U>> for Vector3<T, U> {
+    type Output = Vector3<T, U>;
+    fn sub(self, other: Vector3<T, U>) -> Vector3<T, U> {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
     }
 }
 
-impl_op! { Sub,
-    fn sub(self: Vector3, other: f32) -> Vector3 {
-        Vector3 {
-            x: self.x - other,
-            y: self.y - other,
-            z: self.z - other
-        }
+impl<T: Sub<Output = T> + Copy, U> Sub<T> for Vector3<T, U> {
+    type Output = Vector3<T, U>;
+    fn sub(self, other: T) -> Vector3<T, U> {
+        Vector3::new(self.x - other, self.y - other, self.z - other)
     }
 }
 
-impl_op! { Mul,
-    fn mul(self: Vector3, other: Vector3) -> Vector3 {
-        Vector3 {
-            x: self.x * other.x,
-            y: self.y * other.y,
-            z: self.z * other.z
-        }
+impl<T: Mul<Output = T>, U> Mul<Vector3<T, U>> for Vector3<T, U> {
+    type Output = Vector3<T, U>;
+    fn mul(self, other: Vector3<T, U>) -> Vector3<T, U> {
+        Vector3::new(self.x * other.x, self.y * other.y, self.z * other.z)
     }
 }
 
-impl_op! { Mul,
-    fn mul(self: Vector3, other: f32) -> Vector3 {
-        Vector3 {
-            x: self.x * other,
-            y: self.y * other,
-            z: self.z * other
-        }
+impl<T: Mul<Output = T> + Copy, U> Mul<T> for Vector3<T, U> {
+    type Output = Vector3<T, U>;
+    fn mul(self, other: T) -> Vector3<T, U> {
+        Vector3::new(self.x * other, self.y * other, self.z * other)
     }
 }
 
-impl_op! { Mul,
-    fn mul(self: f32, other: Vector3) -> Vector3 {
-        Vector3 {
-            x: other.x * self,
-            y: other.y * self,
-            z: other.z * self
-        }
+// Preserve the historical `f32 * Vector3` ordering.
+impl<U> Mul<Vector3<f32, U>> for f32 {
+    type Output = Vector3<f32, U>;
+    fn mul(self, other: Vector3<f32, U>) -> Vector3<f32, U> {
+        Vector3::new(other.x * self, other.y * self, other.z * self)
     }
 }
 
-impl_op! { Div,
-    fn div(self: Vector3, other: f32) -> Vector3 {
-        Vector3 {
-            x: self.x / other,
-            y: self.y / other,
-            z: self.z / other
-        }
+impl<T: Div<Output = T> + Copy, U> Div<T> for Vector3<T, U> {
+    type Output = Vector3<T, U>;
+    fn div(self, other: T) -> Vector3<T, U> {
+        Vector3::new(self.x / other, self.y / other, self.z / other)
     }
 }
 
-impl_op! { Neg,
-    fn neg(self: Vector3) -> Vector3 {
-        Vector3 {
-            x: -self.x,
-            y: -self.y,
-            z: -self.z
-        }
+impl<T: Neg<Output = T>, U> Neg for Vector3<T, U> {
+    type Output = Vector3<T, U>;
+    fn neg(self) -> Vector3<T, U> {
+        Vector3::new(-self.x, -self.y, -self.z)
     }
 }
 
-impl_op! { AddAssign,
-    fn add_assign(&mut self: Vector3, other: f32) {
+impl<T: AddAssign + Copy, U> AddAssign<T> for Vector3<T, U> {
+    fn add_assign(&mut self, other: T) {
         self.x += other;
         self.y += other;
         self.z += other;
     }
 }
-    
-impl_op! { SubAssign,
-    fn sub_assign(&mut self: Vector3, other: f32) {
+
+impl<T: SubAssign + Copy, U> SubAssign<T> for Vector3<T, U> {
+    fn sub_assign(&mut self, other: T) {
         self.x -= other;
         self.y -= other;
         self.z -= other;
     }
 }
 
-impl_op! { MulAssign,
-    fn mul_assign(&mut self: Vector3, other: f32) {
+impl<T: MulAssign + Copy, U> MulAssign<T> for Vector3<T, U> {
+    fn mul_assign(&mut self, other: T) {
         self.x *= other;
         self.y *= other;
         self.z *= other;
     }
 }
 
-impl_op! { DivAssign,
-    fn div_assign(&mut self: Vector3, other: f32) {
+impl<T: Div<Output = T> + Copy, U> DivAssign<T> for Vector3<T, U> {
+    fn div_assign(&mut self, other: T) {
         self.x = self.x / other;
         self.y = self.y / other;
-        self.z = self.z / other;   
+        self.z = self.z / other;
+    }
+}
+
+// Serialization
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, U> serde::Serialize for Vector3<T, U> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeTuple;
+
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&self.x)?;
+        tuple.serialize_element(&self.y)?;
+        tuple.serialize_element(&self.z)?;
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, U> serde::Deserialize<'de> for Vector3<T, U> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vector3<T, U>, D::Error> {
+        let [x, y, z] = <[T; 3]>::deserialize(deserializer)?;
+        Ok(Vector3::new(x, y, z))
+    }
+}
+
+// The `#[repr(C)]` layout with a zero-size unit marker is plain-old-data, so
+// slices of vectors can be uploaded to the GPU or `mmap`ed zero-copy.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, U: 'static> bytemuck::Zeroable for Vector3<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod, U: 'static> bytemuck::Pod for Vector3<T, U> {}
+
+// Endian-aware byte encoding for network buffers and files without serde.
+#[cfg(feature = "byteorder")]
+impl Vec3 {
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Vec3> {
+        let x = reader.read_f32::<LittleEndian>()?;
+        let y = reader.read_f32::<LittleEndian>()?;
+        let z = reader.read_f32::<LittleEndian>()?;
+        Ok(Vec3::new(x, y, z))
+    }
+
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_f32::<LittleEndian>(self.x)?;
+        writer.write_f32::<LittleEndian>(self.y)?;
+        writer.write_f32::<LittleEndian>(self.z)
+    }
+
+    pub fn read_from_be<R: Read>(reader: &mut R) -> io::Result<Vec3> {
+        let x = reader.read_f32::<BigEndian>()?;
+        let y = reader.read_f32::<BigEndian>()?;
+        let z = reader.read_f32::<BigEndian>()?;
+        Ok(Vec3::new(x, y, z))
+    }
+
+    pub fn write_to_be<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_f32::<BigEndian>(self.x)?;
+        writer.write_f32::<BigEndian>(self.y)?;
+        writer.write_f32::<BigEndian>(self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn move_towards_steps_without_overshooting() {
+        let stepped = Vec3::move_towards(Vec3::ZERO, Vec3::new(10.0, 0.0, 0.0), 3.0);
+        assert!(close(stepped.x, 3.0));
+        // When the remaining distance is within the step, it lands on target.
+        let arrived = Vec3::move_towards(Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0), 5.0);
+        assert!(close(arrived.x, 2.0));
+    }
+
+    #[test]
+    fn direction_slerp_walks_the_arc() {
+        let mid = Vec3::slerp(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0), 0.5);
+        let half = (0.5f32).sqrt();
+        assert!(close(mid.x, half) && close(mid.y, half) && close(mid.z, 0.0));
+        assert!(close(mid.magnitude(), 1.0));
+    }
+
+    #[test]
+    fn smooth_damp_converges_without_overshooting() {
+        let target = Vec3::new(1.0, 0.0, 0.0);
+        let mut velocity = Vec3::ZERO;
+        let mut current = Vec3::ZERO;
+        for _ in 0..200 {
+            current = Vec3::smooth_damp(current, target, &mut velocity, 0.1, 1e6, 0.02);
+            // It approaches from below and must never shoot past the target.
+            assert!(current.x <= target.x + 1e-4);
+        }
+        assert!(close(current.x, 1.0));
+        assert!(close(velocity.magnitude(), 0.0));
+    }
+
+    #[test]
+    fn smooth_damp_snaps_when_the_target_is_overshot() {
+        // A fast inbound velocity that would carry past the target is clamped
+        // back onto it, zeroing the stored velocity.
+        let target = Vec3::new(1.0, 0.0, 0.0);
+        let mut velocity = Vec3::new(50.0, 0.0, 0.0);
+        let out = Vec3::smooth_damp(Vec3::ZERO, target, &mut velocity, 0.5, 1e6, 0.2);
+        assert!(close(out.x, 1.0));
+        assert!(close(velocity.magnitude(), 0.0));
+    }
+
+    #[test]
+    fn component_ops_work_on_unsigned_integers() {
+        let a: Vector3<u32> = Vector3::new(5, 0, 10);
+        let lo: Vector3<u32> = Vector3::new(1, 2, 3);
+        let hi: Vector3<u32> = Vector3::new(4, 8, 20);
+        assert_eq!(a.clamp(lo, hi), Vector3::new(4, 2, 10));
+        assert_eq!(Vector3::min(a, hi), Vector3::new(4, 0, 10));
+        assert_eq!(Vector3::max(a, lo), Vector3::new(5, 2, 10));
+    }
+
+    #[test]
+    fn integer_vectors_compare_exactly() {
+        let a: Vector3<i32> = Vector3::new(1, -2, 3);
+        assert_eq!(a, Vector3::new(1, -2, 3));
+        assert!(a != Vector3::new(1, -2, 4));
+    }
+
+    #[test]
+    fn indexing_reads_and_writes_components() {
+        let mut v = Vec3::new(1.0, 2.0, 3.0);
+        assert!(close(v[0], 1.0) && close(v[1], 2.0) && close(v[2], 3.0));
+        v[1] = 9.0;
+        assert!(close(v.y, 9.0));
+    }
+}
+
+#[cfg(test)]
+mod ortho_tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-5
+    }
+
+    #[test]
+    fn ortho_normalize_produces_an_orthonormal_pair() {
+        let mut a = Vec3::new(2.0, 0.0, 0.0);
+        let mut b = Vec3::new(1.0, 1.0, 0.0);
+        Vec3::ortho_normalize(&mut a, &mut b);
+
+        assert!(close(a.magnitude(), 1.0));
+        assert!(close(b.magnitude(), 1.0));
+        assert!(close(Vec3::dot(a, b), 0.0));
+    }
+
+    #[test]
+    fn ortho_normalize3_builds_an_orthonormal_basis() {
+        let mut a = Vec3::new(0.0, 0.0, 3.0);
+        let mut b = Vec3::new(0.0, 2.0, 2.0);
+        let mut c = Vec3::ZERO;
+        Vec3::ortho_normalize3(&mut a, &mut b, &mut c);
+
+        for v in &[a, b, c] {
+            assert!(close(v.magnitude(), 1.0));
+        }
+        assert!(close(Vec3::dot(a, b), 0.0));
+        assert!(close(Vec3::dot(a, c), 0.0));
+        assert!(close(Vec3::dot(b, c), 0.0));
+        // The third axis completes a right-handed frame.
+        let expected = Vec3::cross(a, b);
+        assert!(close(c.x, expected.x) && close(c.y, expected.y) && close(c.z, expected.z));
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_as_a_three_element_sequence() {
+        let v = Vec3::new(1.5, -2.0, 7.25);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1.5,-2.0,7.25]");
+        let back: Vec3 = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, v);
     }
 }